@@ -0,0 +1,218 @@
+//! Contains the `Boundary` enum, which is used to determine how a string is split
+//! into words before being converted into a case.
+
+use crate::words::Words;
+
+/// The different ways a string can be split into words based on the location
+/// of boundaries within the string.
+///
+/// Boundaries that correspond to a delimiter character (`Hyphen`, `Underscore`,
+/// and `Space`) consume that character when splitting.  The remaining boundaries
+/// are based on transitions between letters and digits, and do not consume any
+/// characters.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
+pub enum Boundary {
+    /// Splits on `-`, consuming the character when separating words.
+    Hyphen,
+    /// Splits on `_`, consuming the character when separating words.
+    Underscore,
+    /// Splits on ` `, consuming the character when separating words.
+    Space,
+    /// Splits whenever a lowercase letter is followed by an uppercase letter,
+    /// as in `aB`.
+    LowerUpper,
+    /// Splits whenever an uppercase letter is followed by a digit, as in `A1`.
+    UpperDigit,
+    /// Splits whenever a digit is followed by an uppercase letter, as in `1A`.
+    DigitUpper,
+    /// Splits whenever a digit is followed by a lowercase letter, as in `1a`.
+    DigitLower,
+    /// Splits whenever a lowercase letter is followed by a digit, as in `a1`.
+    LowerDigit,
+    /// Splits acronyms from the word that follows them.  A run of two or more
+    /// uppercase letters is kept together as a single word, unless the last
+    /// letter of the run is itself followed by a lowercase letter, in which
+    /// case that last letter starts the next word.  For example, `XMLHttpRequest`
+    /// splits into `XML`, `Http`, and `Request`.
+    Acronyms,
+    /// Splits `s` into words using Unicode word segmentation (UAX #29) instead
+    /// of the char-based rules above, then applies the same upper/lower
+    /// transition rules to each Unicode word, operating on grapheme clusters
+    /// so that combining marks are never split apart.  This correctly handles
+    /// scripts and punctuation that the other boundaries get wrong, at the
+    /// cost of pulling in the `unicode-segmentation` crate.  When present,
+    /// this boundary replaces every other boundary rather than combining
+    /// with them.  Requires the `unicode` feature.
+    #[cfg(feature = "unicode")]
+    UnicodeWords,
+}
+
+/// Splits `s` into a list of [`Words`] wherever one of the given `boundaries`
+/// occurs.  Characters belonging to delimiter boundaries (`Hyphen`,
+/// `Underscore`, `Space`) are removed; the rest of the boundaries only
+/// indicate where to split.
+///
+/// Consecutive or leading/trailing delimiters produce no empty words.
+pub fn split<T>(s: &T, boundaries: &[Boundary]) -> Words
+where
+    T: AsRef<str> + ?Sized,
+{
+    #[cfg(feature = "unicode")]
+    if boundaries.contains(&Boundary::UnicodeWords) {
+        return split_unicode(s.as_ref());
+    }
+
+    let s = s.as_ref();
+
+    let mut delimited = Vec::new();
+    let mut word_start = 0;
+    let chars: Vec<char> = s.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let is_delim = (boundaries.contains(&Boundary::Underscore) && c == '_')
+            || (boundaries.contains(&Boundary::Hyphen) && c == '-')
+            || (boundaries.contains(&Boundary::Space) && c == ' ');
+        if is_delim {
+            if i > word_start {
+                delimited.push(chars[word_start..i].iter().collect::<String>());
+            }
+            word_start = i + 1;
+        }
+    }
+    if word_start < chars.len() {
+        delimited.push(chars[word_start..].iter().collect::<String>());
+    }
+
+    let mut words = Vec::new();
+    for word in delimited {
+        words.extend(split_word(&word, boundaries));
+    }
+    Words::new(words)
+}
+
+/// Splits a single delimiter-free word on the letter/digit transition boundaries.
+fn split_word(word: &str, boundaries: &[Boundary]) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    if chars.is_empty() {
+        return Vec::new();
+    }
+
+    let mut splits = Vec::new();
+    for i in 1..chars.len() {
+        let prev = chars[i - 1];
+        let curr = chars[i];
+        let split_here = (boundaries.contains(&Boundary::LowerUpper)
+            && prev.is_lowercase()
+            && curr.is_uppercase())
+            || (boundaries.contains(&Boundary::UpperDigit)
+                && prev.is_uppercase()
+                && curr.is_ascii_digit())
+            || (boundaries.contains(&Boundary::DigitUpper)
+                && prev.is_ascii_digit()
+                && curr.is_uppercase())
+            || (boundaries.contains(&Boundary::DigitLower)
+                && prev.is_ascii_digit()
+                && curr.is_lowercase())
+            || (boundaries.contains(&Boundary::LowerDigit)
+                && prev.is_lowercase()
+                && curr.is_ascii_digit())
+            || (boundaries.contains(&Boundary::Acronyms)
+                && prev.is_uppercase()
+                && curr.is_uppercase()
+                && chars.get(i + 1).is_some_and(|c| c.is_lowercase()));
+        if split_here {
+            splits.push(i);
+        }
+    }
+
+    let mut words = Vec::with_capacity(splits.len() + 1);
+    let mut start = 0;
+    for idx in splits {
+        words.push(chars[start..idx].iter().collect());
+        start = idx;
+    }
+    words.push(chars[start..].iter().collect());
+    words
+}
+
+/// The `Boundary::UnicodeWords` path: segments `s` into Unicode words, then
+/// further splits on underscores and on grapheme-level upper/lower
+/// transitions, mirroring the algorithm `heck` uses for its cases.
+#[cfg(feature = "unicode")]
+fn split_unicode(s: &str) -> Words {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut words = Vec::new();
+    for unicode_word in s.unicode_words() {
+        for segment in unicode_word.split('_') {
+            if !segment.is_empty() {
+                words.extend(split_unicode_word(segment));
+            }
+        }
+    }
+    Words::new(words)
+}
+
+/// Splits a single Unicode word on upper/lower transitions, grapheme cluster
+/// by grapheme cluster: a boundary is placed before an uppercase cluster that
+/// follows a lowercase one, and a run of uppercase clusters is kept together
+/// unless its last cluster is followed by a lowercase one, in which case that
+/// last cluster starts the next word.
+#[cfg(feature = "unicode")]
+fn split_unicode_word(word: &str) -> Vec<String> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let graphemes: Vec<&str> = word.graphemes(true).collect();
+    if graphemes.is_empty() {
+        return Vec::new();
+    }
+
+    let is_upper = |g: &str| g.chars().next().is_some_and(char::is_uppercase);
+    let is_lower = |g: &str| g.chars().next().is_some_and(char::is_lowercase);
+
+    let mut splits = Vec::new();
+    for i in 1..graphemes.len() {
+        let prev = graphemes[i - 1];
+        let curr = graphemes[i];
+        let split_here = (is_lower(prev) && is_upper(curr))
+            || (is_upper(prev)
+                && is_upper(curr)
+                && graphemes.get(i + 1).is_some_and(|g| is_lower(g)));
+        if split_here {
+            splits.push(i);
+        }
+    }
+
+    let mut words = Vec::with_capacity(splits.len() + 1);
+    let mut start = 0;
+    for idx in splits {
+        words.push(graphemes[start..idx].concat());
+        start = idx;
+    }
+    words.push(graphemes[start..].concat());
+    words
+}
+
+#[cfg(test)]
+#[cfg(feature = "unicode")]
+mod test {
+    use super::*;
+
+    fn words(s: &str) -> Vec<String> {
+        split(s, &[Boundary::UnicodeWords]).into()
+    }
+
+    #[test]
+    fn splits_lower_upper_transition() {
+        assert_eq!(vec!["Hello", "World"], words("HelloWorld"));
+    }
+
+    #[test]
+    fn keeps_acronyms_together() {
+        assert_eq!(vec!["XML", "Http", "Request"], words("XMLHttpRequest"));
+    }
+
+    #[test]
+    fn splits_on_underscore() {
+        assert_eq!(vec!["hello", "World"], words("hello_World"));
+    }
+}
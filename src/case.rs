@@ -0,0 +1,205 @@
+//! Defines the `Case` enum, which represents the different cases this crate
+//! can convert a string into.
+
+use crate::boundary::Boundary;
+use crate::pattern::Pattern;
+use strum_macros::EnumIter;
+
+/// Defines the cases that a string can be converted to by this crate.
+///
+/// Each case is associated with a [`Pattern`], which mutates each word, and
+/// a delimiter, which joins the mutated words back together.  Each case is
+/// also associated with a set of [`Boundary`]s, used by
+/// [`from_case`](crate::Casing::from_case) to split a string that is known to
+/// already be in that case.
+///
+/// ```
+/// use convert_case::{Case, Casing};
+///
+/// assert_eq!("my variable name", "My Variable Name".to_case(Case::Lower));
+/// assert_eq!("MyVariableName", "my variable name".to_case(Case::Pascal));
+/// assert_eq!("my-variable-name", "My Variable Name".to_case(Case::Kebab));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, EnumIter)]
+pub enum Case {
+    /// Lowercase strings are delimited by spaces and all characters are lowercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `my variable name`
+    Lower,
+    /// Uppercase strings are delimited by spaces and all characters are uppercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MY VARIABLE NAME`
+    Upper,
+    /// Title case strings are delimited by spaces, and each word is capitalized.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `My Variable Name`
+    Title,
+    /// Toggle case strings are delimited by spaces, with every word having its
+    /// first letter lowercase and the rest uppercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Toggle](Pattern::Toggle)
+    /// * Example: `mY vARIABLE nAME`
+    Toggle,
+    /// Alternating case strings are delimited by spaces, with characters
+    /// alternating between lowercase and uppercase within each word.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Alternating](Pattern::Alternating)
+    /// * Example: `mY vArIaBlE nAmE`
+    Alternating,
+    /// Camel case strings are lowercase, with the first word lowercase and the
+    /// rest capitalized, and no delimiter between words.
+    /// * Boundaries: [LowerUpper](Boundary::LowerUpper), [DigitUpper](Boundary::DigitUpper), [UpperDigit](Boundary::UpperDigit), [DigitLower](Boundary::DigitLower), [LowerDigit](Boundary::LowerDigit), [Acronyms](Boundary::Acronyms)
+    /// * Pattern: [Camel](Pattern::Camel)
+    /// * Example: `myVariableName`
+    Camel,
+    /// Pascal case strings have every word capitalized, with no delimiter between words.
+    /// * Boundaries: same as [Camel](Case::Camel)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `MyVariableName`
+    Pascal,
+    /// An alias for [Pascal](Case::Pascal).
+    UpperCamel,
+    /// Snake case strings are delimited by underscores and lowercase.
+    /// * Boundaries: [Underscore](Boundary::Underscore)
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `my_variable_name`
+    Snake,
+    /// Upper snake case strings are delimited by underscores and uppercase.
+    /// * Boundaries: [Underscore](Boundary::Underscore)
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MY_VARIABLE_NAME`
+    UpperSnake,
+    /// Kebab case strings are delimited by hyphens and lowercase.
+    /// * Boundaries: [Hyphen](Boundary::Hyphen)
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `my-variable-name`
+    Kebab,
+    /// Cobol case strings are delimited by hyphens and uppercase.
+    /// * Boundaries: [Hyphen](Boundary::Hyphen)
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MY-VARIABLE-NAME`
+    Cobol,
+    /// Train case strings are delimited by hyphens, with every word capitalized.
+    /// * Boundaries: [Hyphen](Boundary::Hyphen)
+    /// * Pattern: [Capital](Pattern::Capital)
+    /// * Example: `My-Variable-Name`
+    Train,
+    /// Flat case strings have no delimiter and are entirely lowercase.  No
+    /// boundary is used to split a flat case string back into words, so
+    /// parsing `from_case(Case::Flat)` treats the whole string as one word.
+    /// * Boundaries: none
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Example: `myvariablename`
+    Flat,
+    /// Upper flat case strings have no delimiter and are entirely uppercase.
+    /// * Boundaries: none
+    /// * Pattern: [Uppercase](Pattern::Uppercase)
+    /// * Example: `MYVARIABLENAME`
+    UpperFlat,
+}
+
+impl Case {
+    /// Returns the delimiter used to join words converted into this case.
+    pub fn delim(&self) -> &'static str {
+        use Case::*;
+        match self {
+            Lower | Upper | Title | Toggle | Alternating => " ",
+            Camel | Pascal | UpperCamel | Flat | UpperFlat => "",
+            Snake | UpperSnake => "_",
+            Kebab | Cobol | Train => "-",
+        }
+    }
+
+    /// Returns the pattern used to mutate each word when converting into this case.
+    pub fn pattern(&self) -> Pattern {
+        use Case::*;
+        match self {
+            Lower | Snake | Kebab | Flat => Pattern::Lowercase,
+            Upper | UpperSnake | Cobol | UpperFlat => Pattern::Uppercase,
+            Title | Pascal | UpperCamel | Train => Pattern::Capital,
+            Camel => Pattern::Camel,
+            Toggle => Pattern::Toggle,
+            Alternating => Pattern::Alternating,
+        }
+    }
+
+    /// Returns the boundaries used to split a string already known to be in
+    /// this case, for use by [`from_case`](crate::Casing::from_case).
+    pub fn boundaries(&self) -> Vec<Boundary> {
+        use Case::*;
+        match self {
+            Lower | Upper | Title | Toggle | Alternating => vec![Boundary::Space],
+            Snake | UpperSnake => vec![Boundary::Underscore],
+            Kebab | Cobol | Train => vec![Boundary::Hyphen],
+            Camel | Pascal | UpperCamel => vec![
+                Boundary::LowerUpper,
+                Boundary::UpperDigit,
+                Boundary::DigitUpper,
+                Boundary::DigitLower,
+                Boundary::LowerDigit,
+                Boundary::Acronyms,
+            ],
+            Flat | UpperFlat => vec![],
+        }
+    }
+
+    /// Returns every case whose conversion is deterministic, that is, every
+    /// case except those backed by randomness.
+    pub fn deterministic_cases() -> Vec<Case> {
+        use strum::IntoEnumIterator;
+        Case::iter().collect()
+    }
+
+    /// Returns every case that `s` unambiguously belongs to: every
+    /// deterministic case for which converting `s` from that case back to
+    /// itself is a no-op.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(vec![Case::Camel], Case::detect("myVariable"));
+    /// ```
+    pub fn detect(s: &str) -> Vec<Case> {
+        use crate::Casing;
+        Case::deterministic_cases()
+            .into_iter()
+            .filter(|case| s.from_case(*case).to_case(*case) == s)
+            .collect()
+    }
+
+    /// Builds a [`CustomCase`](crate::CustomCase) that joins words with
+    /// `delim`, mutated according to `pattern`.  A convenience for defining
+    /// a case this enum doesn't have a variant for.
+    /// ```
+    /// use convert_case::{Case, Casing, Pattern};
+    ///
+    /// let dot_case = Case::custom(".", Pattern::Lowercase);
+    /// assert_eq!("my.variable.name", "MyVariableName".to_case(dot_case));
+    /// ```
+    pub fn custom(delim: impl Into<String>, pattern: Pattern) -> crate::CustomCase {
+        crate::CustomCase::new(delim, pattern)
+    }
+
+    /// The order in which to break ties when more than one case matches the
+    /// same string, from most to least specific.  A plain lowercase word with
+    /// no digits or delimiters trivially satisfies `Lower`, `Snake`, `Kebab`,
+    /// and `Flat` all at once, so those are ranked last.
+    ///
+    /// This ordering alone isn't enough to prefer a case that required an
+    /// actual letter-case transition or delimiter to match: `Camel`'s
+    /// boundaries don't include `-` or `_`, so it trivially (and vacuously)
+    /// matches any delimited lowercase string too, like `Lower`/`Snake`/
+    /// `Kebab` do.  [`Casing::guess_case`](crate::Casing::guess_case) first
+    /// takes the earliest matching case in this order whose boundaries
+    /// actually split the string into more than one word, and only when no
+    /// candidate does falls back to the earliest matching case.
+    pub(crate) fn detection_priority() -> Vec<Case> {
+        use Case::*;
+        vec![
+            UpperSnake, Cobol, UpperFlat, Upper, Pascal, UpperCamel, Train, Title, Toggle,
+            Alternating, Camel, Snake, Kebab, Lower, Flat,
+        ]
+    }
+}
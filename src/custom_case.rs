@@ -0,0 +1,103 @@
+//! Defines the `CaseLike` trait and the `CustomCase` struct, which together
+//! let callers define a case the [`Case`](crate::Case) enum doesn't have a
+//! variant for.
+
+use crate::{Boundary, Case, Pattern};
+
+/// Implemented by anything that can stand in for a [`Case`](crate::Case):
+/// something with a delimiter, a [`Pattern`] used to mutate each word, and a
+/// set of [`Boundary`]s used to parse a string already in that form.
+///
+/// `Case` and [`CustomCase`] both implement this, so either can be passed to
+/// [`Casing::to_case`](crate::Casing::to_case) and
+/// [`Casing::is_case`](crate::Casing::is_case).
+pub trait CaseLike {
+    /// The delimiter placed between words.
+    fn delim(&self) -> String;
+    /// The pattern used to mutate each word.
+    fn pattern(&self) -> Pattern;
+    /// The boundaries used to split a string already in this form, for
+    /// [`Casing::from_case`](crate::Casing::from_case).  `Boundary` only
+    /// recognizes `-`, `_`, and ` ` as delimiter characters, so a case whose
+    /// delimiter is something else (like `CustomCase`'s) can only be parsed
+    /// back out on its letter/digit-transition boundaries.
+    fn boundaries(&self) -> Vec<Boundary>;
+}
+
+impl CaseLike for Case {
+    fn delim(&self) -> String {
+        Case::delim(self).to_string()
+    }
+
+    fn pattern(&self) -> Pattern {
+        Case::pattern(self)
+    }
+
+    fn boundaries(&self) -> Vec<Boundary> {
+        Case::boundaries(self)
+    }
+}
+
+/// A case defined by the caller instead of being one of the [`Case`](crate::Case)
+/// variants: just a delimiter and a [`Pattern`], for things like dot.case,
+/// path/case, or `SCREAMING.DOT.CASE` that this crate doesn't ship a variant
+/// for.
+///
+/// Like a `Case`, it can be passed to
+/// [`Casing::from_case`](crate::Casing::from_case) to parse a string already
+/// in that form.  When `delim` is `-`, `_`, or ` `, `from_case` recognizes it
+/// as a delimiter the same way it would for a built-in `Case`; any other
+/// delimiter is only split on letter-case and digit transitions, same as
+/// [`Case::Camel`](crate::Case::Camel).  [`Boundary`] has no variant for any
+/// other delimiter, so neither `from_case` nor
+/// [`Casing::split_on`](crate::Casing::split_on) splits on it.
+/// ```
+/// use convert_case::{Case, Casing, CustomCase, Pattern};
+///
+/// let screaming_snake = CustomCase::new("_", Pattern::Uppercase);
+/// assert_eq!("MY_VARIABLE_NAME", "MyVariableName".to_case(screaming_snake.clone()));
+/// assert_eq!(
+///     "MyVariableName",
+///     "MY_VARIABLE_NAME".from_case(screaming_snake).to_case(Case::Pascal)
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct CustomCase {
+    delim: String,
+    pattern: Pattern,
+}
+
+impl CustomCase {
+    /// Creates a case that joins words with `delim`, mutated according to `pattern`.
+    pub fn new(delim: impl Into<String>, pattern: Pattern) -> Self {
+        Self {
+            delim: delim.into(),
+            pattern,
+        }
+    }
+}
+
+impl CaseLike for CustomCase {
+    fn delim(&self) -> String {
+        self.delim.clone()
+    }
+
+    fn pattern(&self) -> Pattern {
+        self.pattern
+    }
+
+    fn boundaries(&self) -> Vec<Boundary> {
+        use Boundary::*;
+        let mut boundaries = vec![LowerUpper, UpperDigit, DigitUpper, DigitLower, LowerDigit, Acronyms];
+        match self.delim.as_str() {
+            "-" => boundaries.push(Hyphen),
+            "_" => boundaries.push(Underscore),
+            " " => boundaries.push(Space),
+            // Boundary has no variant for an arbitrary multi-character (or
+            // otherwise unrecognized) delimiter, so from_case can't split on
+            // it; only the letter/digit-transition boundaries above apply.
+            _ => {}
+        }
+        boundaries
+    }
+}
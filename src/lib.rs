@@ -106,8 +106,23 @@
 //! ```
 //! This will add two additional cases: Random and PseudoRandom.  You can read about their
 //! construction in the [Case enum](enum.Case.html).
+//!
+//! # Unicode Feature
+//!
+//! The default boundary rules work on individual `char`s, which means they mishandle some
+//! non-ASCII text.  The `unicode` feature adds
+//! [`Boundary::UnicodeWords`](enum.Boundary.html), which instead segments a string into Unicode
+//! words before splitting, and operates on grapheme clusters rather than individual `char`s.  It
+//! only changes how a string is split into words, not how each word is capitalized, so the Dutch
+//! digraph `ij` is still not capitalized as one letter.  You
+//! can enable this feature by including the following in your `Cargo.toml`.
+//! ```{toml}
+//! [dependencies]
+//! convert_case = { version = "^0.3", features = ["unicode"] }
+//! ```
 
 mod case;
+mod custom_case;
 mod words;
 mod pattern;
 mod boundary;
@@ -115,74 +130,142 @@ mod boundary;
 pub use boundary::Boundary;
 pub use pattern::Pattern;
 pub use case::Case;
-use words::Words;
-
-fn possible_cases(s: &String) -> Vec<Case> {
-    Case::deterministic_cases()
-        .into_iter()
-        .filter(|case| &s.from_case(*case).to_case(*case) == s )
-        .collect()
-}
+pub use custom_case::{CaseLike, CustomCase};
+pub use words::join;
 
 /// Describes items that can be converted into a case.
 ///
 /// Implemented for string slices `&str` and owned strings `String`.
 pub trait Casing {
 
-    /// References `self` and converts to the given case.
-    fn to_case(&self, case: Case) -> String;
+    /// References `self` and converts to the given case.  Accepts a `Case`
+    /// or any other [`CaseLike`], such as a [`CustomCase`].
+    fn to_case<C: CaseLike>(&self, case: C) -> String;
 
     /// Creates a `Converter` struct, which saves information about
-    /// how to parse `self` before converting to a case.
-    fn from_case(&self, case: Case) -> Converter;
-
-    /// Determines if `self` is of the given case.
-    fn is_case(&self, case: Case) -> bool;
-
-    /*
-    Things to add
-
-    // do like https://doc.rust-lang.org/std/primitive.slice.html#method.join
-    fn join(&self, String) -> String;
-
-    fn split_on(&self, Vec<Boundary>) -> Converter;
-
-    fn mutate(&self, Pattern) -> Converter;
-
-    fn add_boundary(&self, Boundary)
-    fn remove_boundary(&self, Boundary)
-    fn add_boundaries(&self, Boundary)
-    fn remove_boundaries(&self, Boundary)
-
-    */
+    /// how to parse `self` before converting to a case.  Accepts a `Case`
+    /// or any other [`CaseLike`], such as a [`CustomCase`], so a string
+    /// already in a custom form can be parsed with its own boundaries
+    /// before converting elsewhere.
+    fn from_case<C: CaseLike>(&self, case: C) -> Converter;
+
+    /// Determines if `self` is of the given case.  Accepts a `Case` or any
+    /// other [`CaseLike`], such as a [`CustomCase`].
+    fn is_case<C: CaseLike>(&self, case: C) -> bool;
+
+    /// Guesses the single most specific case that `self` belongs to, or
+    /// `None` if it doesn't deterministically belong to any.  Useful for
+    /// round-tripping an identifier of unknown case:
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let input = "someVariableName";
+    /// let normalized = input.from_case(input.guess_case().unwrap()).to_case(Case::Snake);
+    /// assert_eq!("some_variable_name", normalized);
+    /// ```
+    fn guess_case(&self) -> Option<Case>;
+
+    /// Splits `self` on `boundaries` and returns the words detected, exposing
+    /// the same segmentation `to_case` uses internally so callers can
+    /// inspect or post-process the word list (filter stop words, inject
+    /// abbreviations, count segments) before joining it themselves with
+    /// [`join`](crate::join).
+    ///
+    /// Named `split_boundaries` rather than `split` so it isn't shadowed by
+    /// the inherent `str`/`String` `split` method, which always wins in
+    /// method call syntax.
+    /// ```
+    /// use convert_case::{Boundary, Casing};
+    ///
+    /// assert_eq!(vec!["my", "String"], "my_String".split_boundaries(&[Boundary::Underscore]));
+    /// ```
+    fn split_boundaries(&self, boundaries: &[Boundary]) -> Vec<String>;
+
+    /// Creates a `Converter` struct initialized with the given boundaries,
+    /// in place of the default boundary set used by `to_case`.
+    fn split_on(&self, boundaries: &[Boundary]) -> Converter;
+
+    /// Creates a `Converter` struct initialized with the given pattern,
+    /// in place of whatever pattern the eventual target `Case` would use.
+    fn mutate(&self, pattern: Pattern) -> Converter;
 }
 
 impl Casing for str {
-    fn to_case(&self, case: Case) -> String {
+    fn to_case<C: CaseLike>(&self, case: C) -> String {
         Converter::new(self.to_string()).to_case(case)
     }
 
-    fn from_case(&self, case: Case) -> Converter {
+    fn from_case<C: CaseLike>(&self, case: C) -> Converter {
         Converter::new_from_case(self.to_string(), case)
     }
 
-    fn is_case(&self, case: Case) -> bool {
+    fn is_case<C: CaseLike>(&self, case: C) -> bool {
         self.to_case(case) == self
     }
+
+    fn guess_case(&self) -> Option<Case> {
+        let matches = Case::detect(self);
+        let ordered: Vec<Case> = Case::detection_priority()
+            .into_iter()
+            .filter(|case| matches.contains(case))
+            .collect();
+        ordered
+            .iter()
+            .find(|case| boundary::split(self, &case.boundaries()).iter().count() > 1)
+            .or_else(|| ordered.first())
+            .copied()
+    }
+
+    fn split_boundaries(&self, boundaries: &[Boundary]) -> Vec<String> {
+        boundary::split(self, boundaries).into()
+    }
+
+    fn split_on(&self, boundaries: &[Boundary]) -> Converter {
+        Converter::new(self.to_string()).set_boundaries(boundaries)
+    }
+
+    fn mutate(&self, pattern: Pattern) -> Converter {
+        Converter::new(self.to_string()).set_pattern(pattern)
+    }
 }
 
 impl Casing for String {
-    fn to_case(&self, case: Case) -> String {
+    fn to_case<C: CaseLike>(&self, case: C) -> String {
         Converter::new(self.to_string()).to_case(case)
     }
 
-    fn from_case(&self, case: Case) -> Converter {
+    fn from_case<C: CaseLike>(&self, case: C) -> Converter {
         Converter::new_from_case(self.to_string(), case)
     }
 
-    fn is_case(&self, case: Case) -> bool {
+    fn is_case<C: CaseLike>(&self, case: C) -> bool {
         &self.to_case(case) == self
     }
+
+    fn guess_case(&self) -> Option<Case> {
+        let matches = Case::detect(self);
+        let ordered: Vec<Case> = Case::detection_priority()
+            .into_iter()
+            .filter(|case| matches.contains(case))
+            .collect();
+        ordered
+            .iter()
+            .find(|case| boundary::split(self, &case.boundaries()).iter().count() > 1)
+            .or_else(|| ordered.first())
+            .copied()
+    }
+
+    fn split_boundaries(&self, boundaries: &[Boundary]) -> Vec<String> {
+        boundary::split(self, boundaries).into()
+    }
+
+    fn split_on(&self, boundaries: &[Boundary]) -> Converter {
+        Converter::new(self.to_string()).set_boundaries(boundaries)
+    }
+
+    fn mutate(&self, pattern: Pattern) -> Converter {
+        Converter::new(self.to_string()).set_pattern(pattern)
+    }
 }
 
 /// Holds information about parsing before converting into a case.
@@ -195,11 +278,15 @@ impl Casing for String {
 /// let title = "ninety-nine_problems".from_case(Case::Snake).to_case(Case::Title);
 /// assert_eq!("Ninety-nine Problems", title);
 /// ```
+#[derive(Clone)]
 pub struct Converter {
     s: String,
     boundaries: Vec<Boundary>,
-    pattern: Pattern,
-    delim: String,
+    // `None` means "use whatever the target `Case` provides"; `Some` means
+    // the user overrode it explicitly, e.g. through `set_pattern`/`set_delim`,
+    // and `to_case` should leave it alone.
+    pattern: Option<Pattern>,
+    delim: Option<String>,
 }
 
 impl Converter {
@@ -214,37 +301,132 @@ impl Converter {
         Self {
             s,
             boundaries: default_boundaries,
-            delim: String::new(),
-            pattern: Pattern::Lowercase, // doesn't matter
+            delim: None,
+            pattern: None,
         }
     }
 
-    fn new_from_case(s: String, case: Case) -> Self {
+    fn new_from_case<C: CaseLike>(s: String, case: C) -> Self {
         Self {
             s,
             boundaries: case.boundaries(),
-            delim: String::new(),
-            pattern: Pattern::Lowercase, // doesn't matter
+            delim: None,
+            pattern: None,
         }
     }
 
+    /// Converts using whatever pattern and delimiter have been set so far,
+    /// falling back to a plain lowercase join if neither `to_case` nor
+    /// `set_pattern`/`set_delim` have been called.
     pub fn convert(self) -> String {
         let words = boundary::split(&self.s, &self.boundaries);
-        self.pattern.mutate(&words).join(&self.delim)
+        let pattern = self.pattern.unwrap_or(Pattern::Lowercase);
+        let delim = self.delim.unwrap_or_default();
+        pattern.mutate(&words).join(&delim)
     }
 
-    pub fn to_case(mut self, case: Case) -> String {
-        self.pattern = case.pattern();
-        self.delim = case.delim().to_string();
+    /// Converts to `case`, which may be a `Case` or any other [`CaseLike`],
+    /// such as a [`CustomCase`].
+    pub fn to_case<C: CaseLike>(mut self, case: C) -> String {
+        if self.pattern.is_none() {
+            self.pattern = Some(case.pattern());
+        }
+        if self.delim.is_none() {
+            self.delim = Some(case.delim());
+        }
         self.convert()
     }
 
-    pub fn from_case(&mut self, case: Case) {
+    pub fn from_case<C: CaseLike>(&mut self, case: C) {
         self.boundaries = case.boundaries();
     }
 
-    pub fn is_case(&self, case: Case) -> bool {
-        Converter::new(self.s.to_string()).to_case(case) == self.s
+    /// Determines if the string being converted is already in `case`, which
+    /// may be a `Case` or any other [`CaseLike`], such as a [`CustomCase`].
+    /// Respects whatever boundaries/pattern/delimiter have already been set
+    /// on this `Converter`, rather than ignoring them.
+    pub fn is_case<C: CaseLike>(&self, case: C) -> bool {
+        self.clone().to_case(case) == self.s
+    }
+
+    /// Sets the boundaries used to split the string, replacing any boundaries
+    /// set previously, whether by `from_case` or another builder method.
+    /// ```
+    /// use convert_case::{Boundary, Case, Casing};
+    ///
+    /// let s = "my-string".from_case(Case::Kebab)
+    ///     .set_boundaries(&[Boundary::Underscore])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("my-string", s);
+    /// ```
+    pub fn set_boundaries(mut self, boundaries: &[Boundary]) -> Self {
+        self.boundaries = boundaries.to_vec();
+        self
+    }
+
+    /// Adds a single boundary to the set already used to split the string.
+    pub fn add_boundary(self, boundary: Boundary) -> Self {
+        self.add_boundaries(&[boundary])
+    }
+
+    /// Adds boundaries to the set already used to split the string, ignoring
+    /// any that are already present.
+    /// ```
+    /// use convert_case::{Boundary, Case, Casing};
+    ///
+    /// let s = "my-string".from_case(Case::Kebab)
+    ///     .add_boundary(Boundary::Acronyms)
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("my_string", s);
+    /// ```
+    pub fn add_boundaries(mut self, boundaries: &[Boundary]) -> Self {
+        for boundary in boundaries {
+            if !self.boundaries.contains(boundary) {
+                self.boundaries.push(*boundary);
+            }
+        }
+        self
+    }
+
+    /// Removes a single boundary from the set used to split the string.
+    pub fn remove_boundary(self, boundary: Boundary) -> Self {
+        self.remove_boundaries(&[boundary])
+    }
+
+    /// Removes boundaries from the set used to split the string.
+    /// ```
+    /// use convert_case::{Boundary, Case, Casing};
+    ///
+    /// let s = "my-string".from_case(Case::Kebab)
+    ///     .remove_boundary(Boundary::Hyphen)
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("my-string", s);
+    /// ```
+    pub fn remove_boundaries(mut self, boundaries: &[Boundary]) -> Self {
+        self.boundaries.retain(|b| !boundaries.contains(b));
+        self
+    }
+
+    /// Sets the delimiter placed between words, overriding whatever delimiter
+    /// the eventual target `Case` would use.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let s = "my-string".from_case(Case::Kebab)
+    ///     .set_delim("::")
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("my::string", s);
+    /// ```
+    pub fn set_delim(mut self, delim: impl Into<String>) -> Self {
+        self.delim = Some(delim.into());
+        self
+    }
+
+    /// Sets the pattern used to mutate each word, overriding whatever pattern
+    /// the eventual target `Case` would use.
+    pub fn set_pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = Some(pattern);
+        self
     }
 
 }
@@ -437,7 +619,7 @@ mod test {
 
     #[test]
     fn detect_many_cases() {
-        let lower_cases_vec = possible_cases(&"asdf".to_string());
+        let lower_cases_vec = Case::detect("asdf");
         let lower_cases_set = HashSet::from_iter(lower_cases_vec.into_iter());
         let mut actual = HashSet::new();
         actual.insert(Case::Lower);
@@ -447,7 +629,7 @@ mod test {
         actual.insert(Case::Flat);
         assert_eq!(lower_cases_set, actual);
 
-        let lower_cases_vec = possible_cases(&"asdfCase".to_string());
+        let lower_cases_vec = Case::detect("asdfCase");
         let lower_cases_set = HashSet::from_iter(lower_cases_vec.into_iter());
         let mut actual = HashSet::new();
         actual.insert(Case::Camel);
@@ -459,9 +641,67 @@ mod test {
         let s = "My String Identifier".to_string();
         for case in Case::deterministic_cases() {
             let new_s = s.from_case(case).to_case(case);
-            let possible = possible_cases(&new_s);
+            let possible = Case::detect(&new_s);
             println!("{} {:?} {:?}", new_s, case, possible);
             assert!(possible.iter().any(|c| c == &case));
         }
     }
+
+    #[test]
+    fn guess_case_prefers_most_specific() {
+        assert_eq!(Some(Case::Camel), "asdf".guess_case());
+        assert_eq!(Some(Case::Camel), "asdfCase".guess_case());
+        assert_eq!(Some(Case::UpperSnake), "MY_VARIABLE_NAME".guess_case());
+    }
+
+    #[test]
+    fn guess_case_prefers_delimiter_over_vacuous_camel() {
+        assert_eq!(Some(Case::Kebab), "my-variable-name".guess_case());
+        assert_eq!(Some(Case::Snake), "my_variable_name".guess_case());
+        assert_eq!(
+            "my_variable_name",
+            "my-variable-name".from_case(Case::Kebab).to_case(Case::Snake)
+        );
+    }
+
+    #[test]
+    fn guess_case_round_trip() {
+        let input = "myVariable22Name";
+        let normalized = input.from_case(input.guess_case().unwrap()).to_case(Case::Snake);
+        assert_eq!("my_variable_22_name", normalized);
+    }
+
+    #[test]
+    fn custom_case_to_case() {
+        let dot_case = CustomCase::new(".", Pattern::Lowercase);
+        assert_eq!("my.variable.name", "MyVariableName".to_case(dot_case.clone()));
+        assert_eq!("my.variable.name", "MyVariableName".to_case(Case::custom(".", Pattern::Lowercase)));
+        assert!("my.variable.name".is_case(dot_case));
+    }
+
+    #[test]
+    fn converter_from_case_accepts_custom_case() {
+        let screaming_snake = CustomCase::new("_", Pattern::Uppercase);
+        let mut converter = "my_variable_name".split_on(&[]);
+        converter.from_case(screaming_snake);
+        assert_eq!("MY_VARIABLE_NAME", converter.to_case(Case::UpperSnake));
+    }
+
+    #[test]
+    fn split_on_boundaries() {
+        assert_eq!(
+            vec!["my", "String"],
+            "my_String".split_boundaries(&[Boundary::Underscore]),
+        );
+        assert_eq!(
+            vec!["my", "Variable", "Name"],
+            "myVariableName".split_boundaries(&Case::Camel.boundaries()),
+        );
+    }
+
+    #[test]
+    fn split_then_join_round_trip() {
+        let words = "MyVariableName".split_boundaries(&Case::Camel.boundaries());
+        assert_eq!("my_variable_name", join(&words, Pattern::Lowercase, "_"));
+    }
 }
@@ -0,0 +1,82 @@
+//! Defines the `Pattern` enum, which describes how the individual words of a
+//! string are mutated before being joined into a particular case.
+
+use crate::words::Words;
+
+/// Describes the mutation applied to each word of a [`Words`] before it is
+/// joined into a cased string.
+#[derive(Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
+pub enum Pattern {
+    /// Lowercases every letter in every word.
+    Lowercase,
+    /// Uppercases every letter in every word.
+    Uppercase,
+    /// Uppercases the first letter of each word, and lowercases the rest.
+    Capital,
+    /// Lowercases the first word entirely, and applies `Capital` to the rest.
+    Camel,
+    /// Lowercases the first letter of each word, and uppercases the rest.
+    Toggle,
+    /// Alternates between lowercase and uppercase for each letter of each
+    /// word, ignoring any non-alphabetic characters and restarting at
+    /// lowercase for every word.
+    Alternating,
+}
+
+impl Pattern {
+    /// Applies the pattern to each word, returning the mutated list of words.
+    pub fn mutate(&self, words: &Words) -> Words {
+        use Pattern::*;
+        let mutated = match self {
+            Lowercase => words.iter().map(|w| w.to_lowercase()).collect(),
+            Uppercase => words.iter().map(|w| w.to_uppercase()).collect(),
+            Capital => words.iter().map(|w| capitalize(w)).collect(),
+            Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize(w) })
+                .collect(),
+            Toggle => words.iter().map(|w| toggle(w)).collect(),
+            Alternating => words.iter().map(|w| alternate(w)).collect(),
+        };
+        Words::new(mutated)
+    }
+}
+
+/// Uppercases the first letter of `s` and lowercases the rest.
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+    }
+}
+
+/// Lowercases the first letter of `s` and uppercases the rest.
+fn toggle(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_lowercase().collect::<String>() + &chars.as_str().to_uppercase(),
+    }
+}
+
+/// Alternates the case of each alphabetic character in `s`, starting with
+/// lowercase and skipping over any non-alphabetic characters.
+fn alternate(s: &str) -> String {
+    let mut lower = true;
+    s.chars()
+        .map(|c| {
+            if !c.is_alphabetic() {
+                return c;
+            }
+            let next = if lower {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                c.to_uppercase().next().unwrap_or(c)
+            };
+            lower = !lower;
+            next
+        })
+        .collect()
+}
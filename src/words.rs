@@ -0,0 +1,48 @@
+//! Defines the `Words` struct, which is an intermediate representation of a
+//! string somewhere between being split by boundaries and joined into a
+//! particular case.
+
+use crate::Pattern;
+
+/// A list of words, obtained by splitting a string on its word boundaries.
+/// A [`Pattern`](crate::Pattern) mutates the individual words, and a
+/// delimiter joins them back into a single cased string.
+#[derive(Debug, Clone)]
+pub struct Words(Vec<String>);
+
+impl Words {
+    /// Creates a new `Words` from a list of words, discarding any that are empty.
+    pub(crate) fn new(words: Vec<String>) -> Self {
+        Words(words.into_iter().filter(|w| !w.is_empty()).collect())
+    }
+
+    /// Joins the words into a single string, placing `delim` between each word.
+    pub fn join(&self, delim: &str) -> String {
+        self.0.join(delim)
+    }
+
+    pub(crate) fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.0.iter()
+    }
+}
+
+impl From<Words> for Vec<String> {
+    fn from(words: Words) -> Self {
+        words.0
+    }
+}
+
+/// Mutates `words` according to `pattern` and joins the result with `delim`,
+/// mirroring [`slice::join`](https://doc.rust-lang.org/std/primitive.slice.html#method.join)
+/// for a list of words you already have, rather than a whole string you'd
+/// have to split first.  The counterpart to
+/// [`Casing::split_boundaries`](crate::Casing::split_boundaries).
+/// ```
+/// use convert_case::{join, Pattern};
+///
+/// assert_eq!("my_cool_var", join(&["my", "cool", "var"], Pattern::Lowercase, "_"));
+/// ```
+pub fn join<S: AsRef<str>>(words: &[S], pattern: Pattern, delim: &str) -> String {
+    let words = Words::new(words.iter().map(|w| w.as_ref().to_string()).collect());
+    pattern.mutate(&words).join(delim)
+}